@@ -25,6 +25,7 @@
 extern crate slog;
 extern crate thread_local;
 extern crate take_mut;
+extern crate crossbeam_channel;
 
 use slog::{Record, RecordStatic, Level, SingleKV, KV, BorrowedKV};
 use slog::{Serializer, OwnedKVList, Key};
@@ -35,15 +36,17 @@ use std::error::Error;
 use std::fmt;
 use std::sync;
 
-use std::sync::{mpsc, Mutex};
+use std::sync::{Mutex, Arc, Condvar};
 use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use take_mut::take;
+use crossbeam_channel::{Sender, Receiver};
 // }}}
 
 // {{{ Serializer
 struct ToSendSerializer {
-    kv: Box<KV + Send>,
+    kv: Box<KV + Send + Sync>,
 }
 
 impl ToSendSerializer {
@@ -51,7 +54,7 @@ impl ToSendSerializer {
         ToSendSerializer { kv: Box::new(()) }
     }
 
-    fn finish(self) -> Box<KV + Send> {
+    fn finish(self) -> Box<KV + Send + Sync> {
         self.kv
     }
 }
@@ -150,8 +153,13 @@ pub enum AsyncError {
     Fatal(Box<std::error::Error>),
 }
 
-impl<T> From<mpsc::TrySendError<T>> for AsyncError {
-    fn from(_: mpsc::TrySendError<T>) -> AsyncError {
+impl<T> From<crossbeam_channel::TrySendError<T>> for AsyncError {
+    fn from(_: crossbeam_channel::TrySendError<T>) -> AsyncError {
+        AsyncError::Full
+    }
+}
+impl<T> From<crossbeam_channel::SendError<T>> for AsyncError {
+    fn from(_: crossbeam_channel::SendError<T>) -> AsyncError {
         AsyncError::Full
     }
 }
@@ -173,12 +181,74 @@ pub type AsyncResult<T> = std::result::Result<T, AsyncError>;
 
 // }}}
 
+// {{{ OverflowStrategy
+/// Strategy used when the internal queue is full
+///
+/// Selected on `AsyncBuilder`/`AsyncCoreBuilder` via `overflow_strategy`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OverflowStrategy {
+    /// Block the calling thread until there is room in the queue
+    ///
+    /// No record is ever lost, at the cost of back-pressuring the logging
+    /// call when the worker thread can't keep up.
+    Block,
+    /// Drop the record and report the number of dropped messages later
+    ///
+    /// This is the default, and matches the historical behavior of `Async`.
+    DropAndReport,
+    /// Drop the record without reporting anything
+    DropSilent,
+}
+
+impl Default for OverflowStrategy {
+    fn default() -> Self {
+        OverflowStrategy::DropAndReport
+    }
+}
+// }}}
+
 // {{{ AsyncCore
+/// Counts `Record`s the worker thread(s) have handed to the wrapped drain
+///
+/// `AsyncCore::flush` snapshots `AsyncCore::enqueued` and waits here for
+/// `processed` to catch up, instead of routing a barrier message through the
+/// shared worker queue: a barrier message only proves *some* worker reached
+/// it, which under `threads(n) > 1` a fast worker can satisfy on another
+/// worker's behalf while a slower one is still mid-record. Counting handles
+/// any number of workers making progress at different speeds.
+struct ProcessedCount {
+    count: Mutex<usize>,
+    changed: Condvar,
+}
+
+impl ProcessedCount {
+    fn new() -> Self {
+        ProcessedCount {
+            count: Mutex::new(0),
+            changed: Condvar::new(),
+        }
+    }
+
+    fn increment(&self) {
+        let mut count = self.count.lock().unwrap();
+        *count += 1;
+        self.changed.notify_all();
+    }
+
+    fn wait_for(&self, target: usize) {
+        let mut count = self.count.lock().unwrap();
+        while *count < target {
+            count = self.changed.wait(count).unwrap();
+        }
+    }
+}
+
 /// `AsyncCore` builder
 pub struct AsyncCoreBuilder<D>
     where D: slog::Drain<Err = slog::Never, Ok = ()> + Send + 'static
 {
     chan_size: usize,
+    overflow_strategy: OverflowStrategy,
     drain: D,
 }
 
@@ -188,35 +258,72 @@ impl<D> AsyncCoreBuilder<D>
     fn new(drain: D) -> Self {
         AsyncCoreBuilder {
             chan_size: 128,
+            overflow_strategy: OverflowStrategy::default(),
             drain: drain,
         }
     }
 
     /// Set channel size used to send logging records to worker thread. When
-    /// buffer is full `AsyncCore` will start returning `AsyncError::Full`.
+    /// buffer is full `AsyncCore` will start returning `AsyncError::Full`
+    /// (unless `overflow_strategy` is set to `OverflowStrategy::Block`).
     pub fn chan_size(mut self, s: usize) -> Self {
         self.chan_size = s;
         self
     }
 
+    /// Set the strategy used when the channel is full
+    ///
+    /// Defaults to `OverflowStrategy::DropAndReport`.
+    pub fn overflow_strategy(mut self, s: OverflowStrategy) -> Self {
+        self.overflow_strategy = s;
+        self
+    }
+
+    /// Use more than one worker thread to drain the queue
+    ///
+    /// Defaults to `1` worker, which keeps the wrapped drain's only
+    /// requirement `Send`. With more than one worker the drain is shared
+    /// across threads instead of owned by a single one, so it additionally
+    /// needs to be `Sync`; that extra bound is requested here, on this
+    /// method, rather than on `AsyncCoreBuilder` itself, so building with
+    /// the default single worker never requires more than `Send` from
+    /// callers who don't opt into this.
+    ///
+    /// Workers share a single bounded queue and each pulls whichever
+    /// `Record` is next, so per-record ordering across the drain is no
+    /// longer guaranteed. Useful when `log` on the wrapped drain does heavy
+    /// work (serialization, compression, blocking IO) and a single worker
+    /// becomes the bottleneck.
+    pub fn threads(self, n: usize) -> ThreadedAsyncCoreBuilder<D>
+        where D: Sync
+    {
+        ThreadedAsyncCoreBuilder {
+            chan_size: self.chan_size,
+            overflow_strategy: self.overflow_strategy,
+            threads: n,
+            drain: self.drain,
+        }
+    }
+
     /// Build `AsyncCore`
     pub fn build(self) -> AsyncCore {
-        let (tx, rx) = mpsc::sync_channel(self.chan_size);
+        let (tx, rx) = crossbeam_channel::bounded(self.chan_size);
+        let drain = self.drain;
+        let subscribers: Arc<Mutex<Vec<Subscriber>>> = Arc::new(Mutex::new(Vec::new()));
+        let has_subscribers = Arc::new(AtomicBool::new(false));
+        let processed = Arc::new(ProcessedCount::new());
+
+        let worker_subscribers = subscribers.clone();
+        let worker_has_subscribers = has_subscribers.clone();
+        let worker_processed = processed.clone();
         let join = thread::spawn(move || loop {
             match rx.recv().unwrap() {
                 AsyncMsg::Record(r) => {
-                    let rs = RecordStatic {
-                        location: &*r.location,
-                        level: r.level,
-                        tag: &r.tag,
-                    };
-
-                    self.drain
-                        .log(&Record::new(&rs,
-                                          &format_args!("{}", r.msg),
-                                          BorrowedKV(&r.kv)),
-                             &r.logger_values)
-                        .unwrap();
+                    if worker_has_subscribers.load(Ordering::Relaxed) {
+                        worker_subscribers.lock().unwrap().retain(|sub| sub.push(&r));
+                    }
+                    r.as_record_values(|rec, vals| drain.log(rec, vals).unwrap());
+                    worker_processed.increment();
                 }
                 AsyncMsg::Finish => return,
             }
@@ -225,11 +332,156 @@ impl<D> AsyncCoreBuilder<D>
         AsyncCore {
             ref_sender: Mutex::new(tx),
             tl_sender: thread_local::ThreadLocal::new(),
-            join: Mutex::new(Some(join)),
+            join: Mutex::new(vec![join]),
+            overflow_strategy: self.overflow_strategy,
+            threads: 1,
+            chan_size: self.chan_size,
+            subscribers: subscribers,
+            has_subscribers: has_subscribers,
+            enqueued: Arc::new(AtomicUsize::new(0)),
+            processed: processed,
         }
     }
 }
 
+/// `AsyncCore` builder configured for more than one worker thread
+///
+/// Returned by `AsyncCoreBuilder::threads`, which is the only way to reach
+/// this type. Carries the extra `Sync` bound multi-worker sharing needs, so
+/// it never leaks onto the single-worker default path.
+pub struct ThreadedAsyncCoreBuilder<D>
+    where D: slog::Drain<Err = slog::Never, Ok = ()> + Send + Sync + 'static
+{
+    chan_size: usize,
+    overflow_strategy: OverflowStrategy,
+    threads: usize,
+    drain: D,
+}
+
+impl<D> ThreadedAsyncCoreBuilder<D>
+    where D: slog::Drain<Err = slog::Never, Ok = ()> + Send + Sync + 'static
+{
+    /// Set channel size used to send logging records to worker threads. When
+    /// buffer is full `AsyncCore` will start returning `AsyncError::Full`
+    /// (unless `overflow_strategy` is set to `OverflowStrategy::Block`).
+    pub fn chan_size(mut self, s: usize) -> Self {
+        self.chan_size = s;
+        self
+    }
+
+    /// Set the strategy used when the channel is full
+    ///
+    /// Defaults to `OverflowStrategy::DropAndReport`.
+    pub fn overflow_strategy(mut self, s: OverflowStrategy) -> Self {
+        self.overflow_strategy = s;
+        self
+    }
+
+    /// Change the number of worker threads draining the queue
+    ///
+    /// See `AsyncCoreBuilder::threads` for details.
+    pub fn threads(mut self, n: usize) -> Self {
+        self.threads = n;
+        self
+    }
+
+    /// Build `AsyncCore`
+    pub fn build(self) -> AsyncCore {
+        let (tx, rx) = crossbeam_channel::bounded(self.chan_size);
+        let drain = Arc::new(self.drain);
+        let threads = std::cmp::max(self.threads, 1);
+        let subscribers: Arc<Mutex<Vec<Subscriber>>> = Arc::new(Mutex::new(Vec::new()));
+        let has_subscribers = Arc::new(AtomicBool::new(false));
+        let processed = Arc::new(ProcessedCount::new());
+
+        let joins = (0..threads)
+            .map(|_| {
+                let rx: Receiver<AsyncMsg> = rx.clone();
+                let drain = drain.clone();
+                let subscribers = subscribers.clone();
+                let has_subscribers = has_subscribers.clone();
+                let processed = processed.clone();
+                thread::spawn(move || loop {
+                    match rx.recv().unwrap() {
+                        AsyncMsg::Record(r) => {
+                            if has_subscribers.load(Ordering::Relaxed) {
+                                subscribers.lock().unwrap().retain(|sub| sub.push(&r));
+                            }
+                            r.as_record_values(|rec, vals| drain.log(rec, vals).unwrap());
+                            processed.increment();
+                        }
+                        AsyncMsg::Finish => return,
+                    }
+                })
+            })
+            .collect();
+
+        AsyncCore {
+            ref_sender: Mutex::new(tx),
+            tl_sender: thread_local::ThreadLocal::new(),
+            join: Mutex::new(joins),
+            overflow_strategy: self.overflow_strategy,
+            threads: threads,
+            chan_size: self.chan_size,
+            subscribers: subscribers,
+            has_subscribers: has_subscribers,
+            enqueued: Arc::new(AtomicUsize::new(0)),
+            processed: processed,
+        }
+    }
+}
+
+struct Subscriber {
+    tx: Sender<AsyncRecord>,
+    dropped: Arc<AtomicUsize>,
+}
+
+impl Subscriber {
+    /// Push a clone of `r` to this subscriber; returns `false` once the
+    /// subscriber's `LogSubscription` has been dropped, so the caller can
+    /// prune it.
+    fn push(&self, r: &AsyncRecord) -> bool {
+        match self.tx.try_send(r.clone()) {
+            Ok(()) => true,
+            Err(crossbeam_channel::TrySendError::Full(_)) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+            Err(crossbeam_channel::TrySendError::Disconnected(_)) => false,
+        }
+    }
+}
+
+/// A tap into the live `AsyncRecord` stream, created by `subscribe()`
+///
+/// Every `Record` the wrapped drain sees is also cloned and pushed here, so
+/// callers can build log-tailing endpoints, in-memory ring buffers, or test
+/// harnesses without replacing the destination drain. Like the main queue,
+/// a subscription has its own bounded channel; records are dropped (and
+/// counted in `dropped()`) once it's full.
+pub struct LogSubscription {
+    rx: Receiver<AsyncRecord>,
+    dropped: Arc<AtomicUsize>,
+}
+
+impl LogSubscription {
+    /// Block until the next `AsyncRecord` arrives
+    pub fn recv(&self) -> Result<AsyncRecord, crossbeam_channel::RecvError> {
+        self.rx.recv()
+    }
+
+    /// Receive the next `AsyncRecord` without blocking
+    pub fn try_recv(&self) -> Result<AsyncRecord, crossbeam_channel::TryRecvError> {
+        self.rx.try_recv()
+    }
+
+    /// Number of records dropped because this subscription's channel was
+    /// full
+    pub fn dropped(&self) -> usize {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
 /// Core of `Async` drain
 ///
 /// See `Async` for documentation.
@@ -237,13 +489,20 @@ impl<D> AsyncCoreBuilder<D>
 /// Wrapping `AsyncCore` allows implementing custom overflow (and other errors)
 /// handling strategy.
 ///
-/// Note: On drop `AsyncCore` waits for it's worker-thread to finish (after handling
-/// all previous `Record`s sent to it). If you can't tolerate the delay, make
-/// sure you drop it eg. in another thread.
+/// Note: On drop `AsyncCore` waits for its worker-thread(s) to finish (after
+/// handling all previous `Record`s sent to it). If you can't tolerate the
+/// delay, make sure you drop it eg. in another thread.
 pub struct AsyncCore {
-    ref_sender: Mutex<mpsc::SyncSender<AsyncMsg>>,
-    tl_sender: thread_local::ThreadLocal<mpsc::SyncSender<AsyncMsg>>,
-    join: Mutex<Option<thread::JoinHandle<()>>>,
+    ref_sender: Mutex<Sender<AsyncMsg>>,
+    tl_sender: thread_local::ThreadLocal<Sender<AsyncMsg>>,
+    join: Mutex<Vec<thread::JoinHandle<()>>>,
+    overflow_strategy: OverflowStrategy,
+    threads: usize,
+    chan_size: usize,
+    subscribers: Arc<Mutex<Vec<Subscriber>>>,
+    has_subscribers: Arc<AtomicBool>,
+    enqueued: Arc<AtomicUsize>,
+    processed: Arc<ProcessedCount>,
 }
 
 impl AsyncCore {
@@ -262,20 +521,75 @@ impl AsyncCore {
         AsyncCoreBuilder::new(drain)
     }
     fn get_sender(&self)
-                  -> Result<&mpsc::SyncSender<AsyncMsg>,
-                            std::sync::PoisonError<sync::MutexGuard<mpsc::SyncSender<AsyncMsg>>>> {
+                  -> Result<&Sender<AsyncMsg>,
+                            std::sync::PoisonError<sync::MutexGuard<Sender<AsyncMsg>>>> {
         self.tl_sender
             .get_or_try(|| Ok(Box::new(self.ref_sender.lock()?.clone())))
     }
 
     /// Send `AsyncRecord` to a worker thread.
+    ///
+    /// Under `OverflowStrategy::Block` this uses the blocking `send`, so a
+    /// full queue back-pressures the caller instead of losing the record.
     fn send(&self, r: AsyncRecord) -> AsyncResult<()> {
         let sender = self.get_sender()?;
 
-        sender.try_send(AsyncMsg::Record(r))?;
+        match self.overflow_strategy {
+            OverflowStrategy::Block => sender.send(AsyncMsg::Record(r))?,
+            OverflowStrategy::DropAndReport | OverflowStrategy::DropSilent => {
+                sender.try_send(AsyncMsg::Record(r))?
+            }
+        }
+
+        // Only counted once the record is actually on the queue, so a
+        // record dropped by `overflow_strategy` above is never waited on.
+        self.enqueued.fetch_add(1, Ordering::SeqCst);
+
+        Ok(())
+    }
 
+    /// Block until every `Record` enqueued before this call has been handed
+    /// to the wrapped drain
+    ///
+    /// Snapshots how many records have been enqueued so far and waits for
+    /// `self.processed` to reach that count. This holds regardless of how
+    /// many workers `threads(n)` spun up or how unevenly they're keeping up
+    /// with each other, unlike routing a barrier message through the shared
+    /// worker queue: that only proves *some* worker reached the barrier, and
+    /// a fast worker can reach it on a slower one's behalf while the slower
+    /// one is still mid-record.
+    pub fn flush(&self) -> AsyncResult<()> {
+        let target = self.enqueued.load(Ordering::SeqCst);
+        self.processed.wait_for(target);
         Ok(())
     }
+
+    /// Subscribe to the live `AsyncRecord` stream
+    ///
+    /// Every record the wrapped drain sees from now on is also cloned and
+    /// pushed to the returned `LogSubscription`, in addition to being
+    /// handed to the wrapped drain as usual.
+    pub fn subscribe(&self) -> LogSubscription {
+        let (tx, rx) = crossbeam_channel::bounded(self.chan_size);
+        let dropped = Arc::new(AtomicUsize::new(0));
+
+        self.subscribers
+            .lock()
+            .unwrap()
+            .push(Subscriber {
+                tx: tx,
+                dropped: dropped.clone(),
+            });
+        // Ordering::Relaxed: workers only need to observe this flag
+        // *eventually* to start checking `subscribers`; the subscriber push
+        // above is already visible through the mutex they'll then lock.
+        self.has_subscribers.store(true, Ordering::Relaxed);
+
+        LogSubscription {
+            rx: rx,
+            dropped: dropped,
+        }
+    }
 }
 
 impl Drain for AsyncCore {
@@ -287,29 +601,68 @@ impl Drain for AsyncCore {
            logger_values: &OwnedKVList)
            -> AsyncResult<()> {
 
+        self.send(AsyncRecord::from_record(record, logger_values))
+    }
+}
+
+/// An owned, sendable copy of a `slog::Record`
+///
+/// `slog::Record` borrows its message, location and key-value list, so it
+/// can't be moved to another thread or stored past the `log` call that
+/// produced it. `AsyncRecord` captures everything it needs (the message, the
+/// location, the tag/level, the record's own kv and the logger's owned
+/// values) so it can be sent across threads and, via `as_record_values`,
+/// replayed against any `Drain` or filter later.
+#[derive(Clone)]
+pub struct AsyncRecord {
+    msg: String,
+    level: Level,
+    location: Box<slog::RecordLocation>,
+    tag: String,
+    logger_values: OwnedKVList,
+    kv: Arc<KV + Send + Sync>,
+}
+
+impl AsyncRecord {
+    /// Capture a `slog::Record` and its logger's owned values
+    ///
+    /// The result owns everything it needs and can be sent to another
+    /// thread, buffered, or replayed later via `as_record_values`.
+    pub fn from_record(record: &Record, logger_values: &OwnedKVList) -> Self {
         let mut ser = ToSendSerializer::new();
         record.kv()
             .serialize(record, &mut ser)
             .expect("`ToSendSerializer` can't fail");
 
-        self.send(AsyncRecord {
+        AsyncRecord {
             msg: fmt::format(*record.msg()),
             level: record.level(),
             location: Box::new(*record.location()),
             tag: String::from(record.tag()),
             logger_values: logger_values.clone(),
-            kv: ser.finish(),
-        })
+            kv: Arc::from(ser.finish()),
+        }
     }
-}
 
-struct AsyncRecord {
-    msg: String,
-    level: Level,
-    location: Box<slog::RecordLocation>,
-    tag: String,
-    logger_values: OwnedKVList,
-    kv: Box<KV + Send>,
+    /// Rebuild a `Record`/`OwnedKVList` pair from this `AsyncRecord` and pass
+    /// them to `f`
+    ///
+    /// This is the replay path: `f` sees the same `(&Record, &OwnedKVList)`
+    /// pair a `Drain::log` implementation would see for the original record,
+    /// so the captured record can be buffered, filtered and fed to any
+    /// `Drain` after the fact.
+    pub fn as_record_values<F, R>(&self, f: F) -> R
+        where F: FnOnce(&Record, &OwnedKVList) -> R
+    {
+        let rs = RecordStatic {
+            location: &*self.location,
+            level: self.level,
+            tag: &self.tag,
+        };
+
+        f(&Record::new(&rs, &format_args!("{}", self.msg), BorrowedKV(&self.kv)),
+          &self.logger_values)
+    }
 }
 
 enum AsyncMsg {
@@ -321,16 +674,20 @@ impl Drop for AsyncCore {
     fn drop(&mut self) {
         let _err: Result<(), Box<std::error::Error>> = {
             || {
-                let _ = self.get_sender()?.send(AsyncMsg::Finish);
-                self.join
-                    .lock()?
-                    .take()
-                    .unwrap()
-                    .join()
-                    .map_err(|_| {
+                // Every worker shares the same queue, so `Finish` must be
+                // sent once per worker (broadcasting it) for all of them to
+                // observe it and return.
+                let sender = self.get_sender()?;
+                for _ in 0..self.threads {
+                    let _ = sender.send(AsyncMsg::Finish);
+                }
+
+                for join in self.join.lock()?.drain(..) {
+                    join.join().map_err(|_| {
                         io::Error::new(io::ErrorKind::BrokenPipe,
                                        "Logging thread worker join error")
                     })?;
+                }
 
                 Ok(())
             }
@@ -354,16 +711,81 @@ impl<D> AsyncBuilder<D>
     }
 
     /// Set channel size used to send logging records to worker thread. When
-    /// buffer is full `AsyncCore` will start returning `AsyncError::Full`.
+    /// buffer is full `AsyncCore` will start returning `AsyncError::Full`
+    /// (unless `overflow_strategy` is set to `OverflowStrategy::Block`).
     pub fn chan_size(self, s: usize) -> Self {
         AsyncBuilder { core: self.core.chan_size(s) }
     }
 
+    /// Set the strategy used when the channel is full
+    ///
+    /// Defaults to `OverflowStrategy::DropAndReport`.
+    pub fn overflow_strategy(self, s: OverflowStrategy) -> Self {
+        AsyncBuilder { core: self.core.overflow_strategy(s) }
+    }
+
+    /// Use more than one worker thread to drain the queue
+    ///
+    /// See `AsyncCoreBuilder::threads` for details, including why this
+    /// requires the wrapped drain to also be `Sync`.
+    pub fn threads(self, n: usize) -> ThreadedAsyncBuilder<D>
+        where D: Sync
+    {
+        ThreadedAsyncBuilder { core: self.core.threads(n) }
+    }
+
+    /// Complete building `Async`
+    pub fn build(self) -> Async {
+        let overflow_strategy = self.core.overflow_strategy;
+        Async {
+            core: self.core.build(),
+            dropped: AtomicUsize::new(0),
+            overflow_strategy: overflow_strategy,
+        }
+    }
+}
+
+/// `Async` builder configured for more than one worker thread
+///
+/// Returned by `AsyncBuilder::threads`; see `ThreadedAsyncCoreBuilder` for
+/// why this carries the extra `Sync` bound instead of `AsyncBuilder` itself.
+pub struct ThreadedAsyncBuilder<D>
+    where D: slog::Drain<Err = slog::Never, Ok = ()> + Send + Sync + 'static
+{
+    core: ThreadedAsyncCoreBuilder<D>,
+}
+
+impl<D> ThreadedAsyncBuilder<D>
+    where D: slog::Drain<Err = slog::Never, Ok = ()> + Send + Sync + 'static
+{
+    /// Set channel size used to send logging records to worker threads. When
+    /// buffer is full `AsyncCore` will start returning `AsyncError::Full`
+    /// (unless `overflow_strategy` is set to `OverflowStrategy::Block`).
+    pub fn chan_size(self, s: usize) -> Self {
+        ThreadedAsyncBuilder { core: self.core.chan_size(s) }
+    }
+
+    /// Set the strategy used when the channel is full
+    ///
+    /// Defaults to `OverflowStrategy::DropAndReport`.
+    pub fn overflow_strategy(self, s: OverflowStrategy) -> Self {
+        ThreadedAsyncBuilder { core: self.core.overflow_strategy(s) }
+    }
+
+    /// Change the number of worker threads draining the queue
+    ///
+    /// See `AsyncCoreBuilder::threads` for details.
+    pub fn threads(self, n: usize) -> Self {
+        ThreadedAsyncBuilder { core: self.core.threads(n) }
+    }
+
     /// Complete building `Async`
     pub fn build(self) -> Async {
+        let overflow_strategy = self.core.overflow_strategy;
         Async {
             core: self.core.build(),
             dropped: AtomicUsize::new(0),
+            overflow_strategy: overflow_strategy,
         }
     }
 }
@@ -373,8 +795,11 @@ impl<D> AsyncBuilder<D>
 /// `Async` will send all the logging records to a wrapped drain running in
 /// another thread.
 ///
-/// On `AsyncError::Full` returned by `AsyncCore` used internally, `Async` will
-/// drop overflowing `Records` and report number of dropped messages.
+/// On `AsyncError::Full` returned by `AsyncCore` used internally, `Async`
+/// follows its configured `OverflowStrategy`: by default (`DropAndReport`)
+/// it drops the overflowing `Record` and reports the number of dropped
+/// messages, but it can instead block the caller (`Block`) or drop silently
+/// (`DropSilent`). See `AsyncBuilder::overflow_strategy`.
 ///
 /// Note: On drop `Async` waits for it's worker-thread to finish (after handling
 /// all previous `Record`s sent to it). If you can't tolerate the delay, make
@@ -382,6 +807,7 @@ impl<D> AsyncBuilder<D>
 pub struct Async {
     core: AsyncCore,
     dropped: AtomicUsize,
+    overflow_strategy: OverflowStrategy,
 }
 
 impl Async {
@@ -403,6 +829,24 @@ impl Async {
         AsyncBuilder::new(drain)
     }
 
+    /// Block until every `Record` queued before this call has been handed to
+    /// the wrapped drain
+    ///
+    /// Records dropped on overflow were never queued and are not waited for.
+    /// See `AsyncCore::flush` for the guarantee this provides. Useful to
+    /// make sure logs are persisted at a checkpoint (e.g. before exit or
+    /// panic handling) without tearing down the drain.
+    pub fn flush(&self) -> AsyncResult<()> {
+        self.core.flush()
+    }
+
+    /// Subscribe to the live `AsyncRecord` stream
+    ///
+    /// See `AsyncCore::subscribe` for details.
+    pub fn subscribe(&self) -> LogSubscription {
+        self.core.subscribe()
+    }
+
     fn push_dropped(&self, logger_values: &OwnedKVList) -> AsyncResult<()> {
         let dropped = self.dropped.swap(0, Ordering::Relaxed);
         if dropped > 0 {
@@ -435,12 +879,16 @@ impl Drain for Async {
            logger_values: &OwnedKVList)
            -> AsyncResult<()> {
 
-        self.push_dropped(logger_values)?;
+        if self.overflow_strategy == OverflowStrategy::DropAndReport {
+            self.push_dropped(logger_values)?;
+        }
 
         match self.core.log(record, logger_values) {
             Ok(()) => {}
             Err(AsyncError::Full) => {
-                self.dropped.fetch_add(1, Ordering::Relaxed);
+                if self.overflow_strategy == OverflowStrategy::DropAndReport {
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                }
                 return Ok(());
             }
             Err(e) => return Err(e),
@@ -452,10 +900,198 @@ impl Drain for Async {
 
 impl Drop for Async {
     fn drop(&mut self) {
-        let _ = self.push_dropped(&o!().into());
+        if self.overflow_strategy == OverflowStrategy::DropAndReport {
+            let _ = self.push_dropped(&o!().into());
+        }
     }
 }
 
 // }}}
 
+// {{{ Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    /// A drain that blocks inside `log()` until released, after first
+    /// reporting (via `entered`) that it has started. This lets a test
+    /// deterministically wait until the worker thread is busy, instead of
+    /// sleeping and hoping.
+    struct GatedDrain {
+        entered: Mutex<mpsc::Sender<()>>,
+        proceed: Mutex<mpsc::Receiver<()>>,
+    }
+
+    impl Drain for GatedDrain {
+        type Ok = ();
+        type Err = slog::Never;
+
+        fn log(&self, _record: &Record, _values: &OwnedKVList) -> Result<(), slog::Never> {
+            self.entered.lock().unwrap().send(()).unwrap();
+            self.proceed.lock().unwrap().recv().unwrap();
+            Ok(())
+        }
+    }
+
+    fn gated_drain() -> (GatedDrain, mpsc::Receiver<()>, mpsc::Sender<()>) {
+        let (entered_tx, entered_rx) = mpsc::channel();
+        let (proceed_tx, proceed_rx) = mpsc::channel();
+        (GatedDrain {
+             entered: Mutex::new(entered_tx),
+             proceed: Mutex::new(proceed_rx),
+         },
+         entered_rx,
+         proceed_tx)
+    }
+
+    #[test]
+    fn overflow_drop_and_report_counts_dropped_records() {
+        let (drain, entered_rx, proceed_tx) = gated_drain();
+        let async_drain = Async::new(drain).chan_size(1).build();
+        let values = o!().into();
+
+        // Picked up by the worker immediately, which then blocks inside
+        // `log()`; once `entered` fires we know the queue is empty.
+        async_drain.log(&record!(Level::Info, "test", &format_args!("hello"), b!()),
+                        &values)
+            .unwrap();
+        entered_rx.recv().unwrap();
+
+        // The bounded(1) queue now has room for exactly one more record.
+        async_drain.log(&record!(Level::Info, "test", &format_args!("hello"), b!()),
+                        &values)
+            .unwrap();
+        // This one overflows: `DropAndReport` (the default) drops it and
+        // counts it instead of returning an error.
+        async_drain.log(&record!(Level::Info, "test", &format_args!("hello"), b!()),
+                        &values)
+            .unwrap();
+
+        proceed_tx.send(()).unwrap();
+        proceed_tx.send(()).unwrap();
+        async_drain.flush().unwrap();
+
+        assert_eq!(async_drain.dropped.load(Ordering::SeqCst), 1);
+
+        // Dropping `async_drain` reports the dropped count as one more record,
+        // which the gated drain must also be released for.
+        proceed_tx.send(()).unwrap();
+    }
+
+    #[test]
+    fn overflow_block_strategy_never_drops() {
+        let (drain, entered_rx, proceed_tx) = gated_drain();
+        let async_drain = Arc::new(Async::new(drain)
+            .chan_size(1)
+            .overflow_strategy(OverflowStrategy::Block)
+            .build());
+        let values = o!().into();
+
+        async_drain.log(&record!(Level::Info, "test", &format_args!("hello"), b!()),
+                        &values)
+            .unwrap();
+        entered_rx.recv().unwrap();
+        // fills the 1-slot queue
+        async_drain.log(&record!(Level::Info, "test", &format_args!("hello"), b!()),
+                        &values)
+            .unwrap();
+
+        // A third record would overflow; under `Block` it must wait for
+        // room instead of being dropped, so send it from another thread.
+        let blocked = async_drain.clone();
+        let sender = thread::spawn(move || {
+            let values = o!().into();
+            blocked.log(&record!(Level::Info, "test", &format_args!("hello"), b!()),
+                        &values)
+                .unwrap();
+        });
+
+        proceed_tx.send(()).unwrap();
+        proceed_tx.send(()).unwrap();
+        proceed_tx.send(()).unwrap();
+
+        sender.join().unwrap();
+        async_drain.flush().unwrap();
+
+        assert_eq!(async_drain.dropped.load(Ordering::SeqCst), 0);
+    }
+
+    /// A drain where exactly one record (whichever one a worker happens to
+    /// dequeue first) is slow; every other record is instant. A `flush()`
+    /// that's only proven *a* worker reached a shared barrier (rather than
+    /// that every enqueued record was actually processed) tends to race
+    /// ahead of whichever worker is stuck on the slow one.
+    struct UnevenLatencyDrain {
+        dequeued: Arc<AtomicUsize>,
+        processed: Arc<AtomicUsize>,
+    }
+
+    impl Drain for UnevenLatencyDrain {
+        type Ok = ();
+        type Err = slog::Never;
+
+        fn log(&self, _record: &Record, _values: &OwnedKVList) -> Result<(), slog::Never> {
+            if self.dequeued.fetch_add(1, Ordering::SeqCst) == 0 {
+                thread::sleep(Duration::from_millis(150));
+            }
+            self.processed.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn flush_waits_for_every_worker_thread_even_with_one_slow_record() {
+        let dequeued = Arc::new(AtomicUsize::new(0));
+        let processed = Arc::new(AtomicUsize::new(0));
+        let async_drain = Async::new(UnevenLatencyDrain {
+                dequeued: dequeued,
+                processed: processed.clone(),
+            })
+            .threads(4)
+            .chan_size(64)
+            .build();
+        let values = o!().into();
+
+        for _ in 0..16 {
+            async_drain.log(&record!(Level::Info, "test", &format_args!("hello"), b!()),
+                            &values)
+                .unwrap();
+        }
+
+        async_drain.flush().unwrap();
+
+        assert_eq!(processed.load(Ordering::SeqCst), 16);
+    }
+
+    #[test]
+    fn subscribe_tees_records_and_counts_overflow() {
+        // `Block` guarantees every `log()` call below actually reaches the
+        // worker (and thus the subscriber tee), regardless of how fast the
+        // test runs relative to the worker thread.
+        let async_drain = Async::new(slog::Discard)
+            .chan_size(4)
+            .overflow_strategy(OverflowStrategy::Block)
+            .build();
+        let sub = async_drain.subscribe();
+        let values = o!().into();
+
+        // The subscription's channel shares `chan_size` (4); since nothing
+        // reads from it yet, only the first 4 of these 6 records fit.
+        for _ in 0..6 {
+            async_drain.log(&record!(Level::Info, "test", &format_args!("hello"), b!()),
+                            &values)
+                .unwrap();
+        }
+        async_drain.flush().unwrap();
+
+        assert_eq!(sub.dropped(), 2);
+
+        let got = sub.recv().unwrap();
+        got.as_record_values(|r, _| assert_eq!(fmt::format(*r.msg()), "hello"));
+    }
+}
+// }}}
+
 // vim: foldmethod=marker foldmarker={{{,}}}